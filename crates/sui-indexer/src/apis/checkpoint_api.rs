@@ -0,0 +1,107 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use diesel::prelude::*;
+use diesel::sql_types::BigInt;
+use jsonrpsee::core::{async_trait, RpcResult};
+use jsonrpsee::proc_macros::rpc;
+
+use crate::errors::IndexerError;
+use crate::metrics::IndexerMetrics;
+use crate::{get_pg_pool_connection, PgConnectionPool};
+
+#[rpc(server, namespace = "checkpoint")]
+pub trait CheckpointApi {
+    /// Returns the sequence number of the most recently indexed checkpoint.
+    #[method(name = "getLatestCheckpointSequenceNumber")]
+    async fn get_latest_checkpoint_sequence_number(&self) -> RpcResult<i64>;
+}
+
+pub struct CheckpointApiImpl {
+    pg_connection_pool: Arc<PgConnectionPool>,
+    pg_blocking_pool: Arc<rayon::ThreadPool>,
+    metrics: Arc<IndexerMetrics>,
+}
+
+impl CheckpointApiImpl {
+    /// Name used by [`crate::config::ApiSet`] to decide whether this module
+    /// should be registered on a given JSON-RPC transport.
+    pub const NAME: &'static str = "checkpoint";
+
+    pub fn new(
+        pg_connection_pool: Arc<PgConnectionPool>,
+        pg_blocking_pool: Arc<rayon::ThreadPool>,
+        metrics: Arc<IndexerMetrics>,
+    ) -> Self {
+        Self {
+            pg_connection_pool,
+            pg_blocking_pool,
+            metrics,
+        }
+    }
+
+    /// Runs `f` on the dedicated blocking pool rather than the async
+    /// runtime's own worker threads. Awaits a `oneshot` for the result
+    /// instead of blocking the calling task on a synchronous `recv`, which
+    /// would just move the runtime-starvation problem onto the task that's
+    /// supposed to be freed up.
+    async fn spawn_blocking<F, T>(&self, f: F) -> Result<T, IndexerError>
+    where
+        F: FnOnce() -> Result<T, IndexerError> + Send + 'static,
+        T: Send + 'static,
+    {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.pg_blocking_pool.spawn(move || {
+            let _ = tx.send(f());
+        });
+        rx.await.map_err(|e| {
+            IndexerError::PgPoolConnectionError(format!(
+                "Blocking pool task was dropped before completing: {:?}",
+                e
+            ))
+        })?
+    }
+}
+
+#[async_trait]
+impl CheckpointApiServer for CheckpointApiImpl {
+    async fn get_latest_checkpoint_sequence_number(&self) -> RpcResult<i64> {
+        self.metrics
+            .observe_rpc(
+                "checkpoint_getLatestCheckpointSequenceNumber",
+                || async {
+                    let pg_connection_pool = self.pg_connection_pool.clone();
+                    let metrics = self.metrics.clone();
+                    self.spawn_blocking(move || {
+                        let mut conn = get_pg_pool_connection(pg_connection_pool, &metrics)?;
+                        get_latest_checkpoint_sequence_number(&mut conn)
+                    })
+                    .await
+                },
+            )
+            .await
+            .map_err(Into::into)
+    }
+}
+
+#[derive(QueryableByName)]
+struct MaxSequenceNumber {
+    #[diesel(sql_type = BigInt)]
+    max: i64,
+}
+
+fn get_latest_checkpoint_sequence_number(
+    conn: &mut diesel::pg::PgConnection,
+) -> Result<i64, IndexerError> {
+    diesel::sql_query("SELECT COALESCE(MAX(sequence_number), 0) AS max FROM checkpoints")
+        .get_result::<MaxSequenceNumber>(conn)
+        .map(|row| row.max)
+        .map_err(|e| {
+            IndexerError::PgPoolConnectionError(format!(
+                "Failed to query latest checkpoint sequence number: {:?}",
+                e
+            ))
+        })
+}
@@ -0,0 +1,74 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use jsonrpsee::core::{async_trait, RpcResult};
+use jsonrpsee::proc_macros::rpc;
+use sui_sdk::SuiClient;
+use tracing::warn;
+
+use crate::errors::IndexerError;
+use crate::metrics::IndexerMetrics;
+use crate::{get_pg_pool_connection, PgConnectionPool};
+
+#[rpc(server, namespace = "indexer")]
+pub trait HealthApi {
+    /// Reports whether the fullnode RPC client and the PG connection pool
+    /// are both reachable. Returns an error describing whichever dependency
+    /// failed instead of a boolean, so the caller sees the actual cause.
+    #[method(name = "health")]
+    async fn health(&self) -> RpcResult<()>;
+}
+
+/// Backs the `indexer_health` RPC used as a readiness/liveness check: it
+/// reports healthy only if both the fullnode RPC client and the PG
+/// connection pool are reachable.
+pub struct HealthApiImpl {
+    rpc_client: SuiClient,
+    pg_connection_pool: Arc<PgConnectionPool>,
+    metrics: Arc<IndexerMetrics>,
+}
+
+impl HealthApiImpl {
+    pub fn new(
+        rpc_client: SuiClient,
+        pg_connection_pool: Arc<PgConnectionPool>,
+        metrics: Arc<IndexerMetrics>,
+    ) -> Self {
+        Self {
+            rpc_client,
+            pg_connection_pool,
+            metrics,
+        }
+    }
+
+    /// Checks that the fullnode RPC client and PG connection pool are both
+    /// reachable, returning a descriptive [`IndexerError`] for whichever
+    /// dependency failed.
+    async fn check(&self) -> Result<(), IndexerError> {
+        self.rpc_client
+            .read_api()
+            .get_chain_identifier()
+            .await
+            .map_err(|e| {
+                warn!("Health check failed to reach fullnode RPC: {:?}", e);
+                IndexerError::RpcClientInitError(format!(
+                    "Fullnode RPC client is not reachable: {:?}",
+                    e
+                ))
+            })?;
+
+        get_pg_pool_connection(self.pg_connection_pool.clone(), &self.metrics).map(|_| ())
+    }
+}
+
+#[async_trait]
+impl HealthApiServer for HealthApiImpl {
+    async fn health(&self) -> RpcResult<()> {
+        self.metrics
+            .observe_rpc("indexer_health", || self.check())
+            .await
+            .map_err(Into::into)
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use jsonrpsee::types::ErrorObjectOwned;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum IndexerError {
+    #[error("Indexer config error: {0}")]
+    ConfigError(String),
+
+    #[error("Indexer failed to initialize fullnode RPC client: {0}")]
+    RpcClientInitError(String),
+
+    #[error("Indexer failed to initialize PG connection pool: {0}")]
+    PgConnectionPoolInitError(String),
+
+    #[error("Indexer failed to get a connection from the PG connection pool: {0}")]
+    PgPoolConnectionError(String),
+
+    #[error("Indexer failed to build or start the JSON-RPC server: {0}")]
+    JsonRpcServerError(String),
+}
+
+/// Lets RPC method handlers return `IndexerError` directly and propagate it
+/// to callers as a JSON-RPC error response via `?`.
+impl From<IndexerError> for ErrorObjectOwned {
+    fn from(e: IndexerError) -> Self {
+        ErrorObjectOwned::owned(
+            jsonrpsee::types::error::ErrorCode::InternalError.code(),
+            e.to_string(),
+            None::<()>,
+        )
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::task::{Context, Poll};
+
+use http::{Request, Response, StatusCode};
+use tower::{Layer, Service};
+
+/// Rejects requests whose `Host` header isn't in `allowed_hosts`, mirroring
+/// how mature JSON-RPC nodes gate which callers can reach RPCs on a public
+/// interface. Built from plain `tower`/`http` types (rather than a
+/// hypothetical `set_allowed_hosts` on the JSON-RPC server builder) so it
+/// can be layered onto any HTTP service, including one built by
+/// `sui_json_rpc::JsonRpcServerBuilder::set_http_middleware`.
+#[derive(Clone)]
+pub struct HostFilterLayer {
+    allowed_hosts: Vec<String>,
+}
+
+impl HostFilterLayer {
+    pub fn new(allowed_hosts: Vec<String>) -> Self {
+        Self { allowed_hosts }
+    }
+}
+
+impl<S> Layer<S> for HostFilterLayer {
+    type Service = HostFilterService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        HostFilterService {
+            inner,
+            allowed_hosts: self.allowed_hosts.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct HostFilterService<S> {
+    inner: S,
+    allowed_hosts: Vec<String>,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for HostFilterService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    ResBody: Default,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = futures::future::Either<
+        S::Future,
+        std::future::Ready<Result<S::Response, S::Error>>,
+    >;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let host = req
+            .headers()
+            .get(http::header::HOST)
+            .and_then(|h| h.to_str().ok())
+            .unwrap_or_default();
+
+        if self.allowed_hosts.iter().any(|allowed| allowed == host) {
+            futures::future::Either::Left(self.inner.call(req))
+        } else {
+            let mut response = Response::default();
+            *response.status_mut() = StatusCode::FORBIDDEN;
+            futures::future::Either::Right(std::future::ready(Ok(response)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::Request;
+    use tower::{service_fn, ServiceExt};
+
+    async fn echo(_req: Request<()>) -> Result<Response<()>, std::convert::Infallible> {
+        Ok(Response::new(()))
+    }
+
+    #[tokio::test]
+    async fn rejects_hosts_outside_the_allowlist() {
+        let layer = HostFilterLayer::new(vec!["rpc.example.com".to_string()]);
+        let mut svc = layer.layer(service_fn(echo));
+
+        let req = Request::builder()
+            .header(http::header::HOST, "evil.example.com")
+            .body(())
+            .unwrap();
+
+        let res = svc.ready().await.unwrap().call(req).await.unwrap();
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn allows_hosts_in_the_allowlist() {
+        let layer = HostFilterLayer::new(vec!["rpc.example.com".to_string()]);
+        let mut svc = layer.layer(service_fn(echo));
+
+        let req = Request::builder()
+            .header(http::header::HOST, "rpc.example.com")
+            .body(())
+            .unwrap();
+
+        let res = svc.ready().await.unwrap().call(req).await.unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+}
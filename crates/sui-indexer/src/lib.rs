@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::Result;
-use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::net::SocketAddr;
 use std::sync::Arc;
 use sui_json_rpc::{JsonRpcServerBuilder, ServerHandle};
 use sui_sdk::{SuiClient, SuiClientBuilder};
@@ -12,11 +12,15 @@ use backoff::ExponentialBackoff;
 use diesel::pg::PgConnection;
 use diesel::prelude::*;
 use diesel::r2d2::{ConnectionManager, Pool, PooledConnection};
+use http::HeaderValue;
 use prometheus::Registry;
+use tower_http::cors::AllowOrigin;
 use tracing::{info, warn};
 
 pub mod apis;
+pub mod config;
 pub mod errors;
+pub mod http_filter;
 pub mod metrics;
 pub mod models;
 pub mod schema;
@@ -26,14 +30,15 @@ pub type PgConnectionPool = Pool<ConnectionManager<PgConnection>>;
 pub type PgPoolConnection = PooledConnection<ConnectionManager<PgConnection>>;
 
 use crate::apis::checkpoint_api::CheckpointApiImpl;
+use crate::apis::health_api::HealthApiImpl;
+use crate::config::IndexerConfig;
+use crate::metrics::IndexerMetrics;
 use errors::IndexerError;
-// TODO: placeholder, read from env or config file.
-pub const FAKE_PKG_VERSION: &str = "0.0.0";
 
-pub async fn new_rpc_client(http_url: String) -> Result<SuiClient, IndexerError> {
+pub async fn new_rpc_client(config: &IndexerConfig) -> Result<SuiClient, IndexerError> {
     info!("Getting new RPC client...");
     SuiClientBuilder::default()
-        .build(http_url)
+        .build(config.rpc_client_url.clone())
         .await
         .map_err(|e| {
             warn!("Failed to get new RPC client with error: {:?}", e);
@@ -48,26 +53,70 @@ pub fn establish_connection(db_url: String) -> PgConnection {
     PgConnection::establish(&db_url).unwrap_or_else(|_| panic!("Error connecting to {}", db_url))
 }
 
-pub async fn new_pg_connection_pool(db_url: String) -> Result<Arc<PgConnectionPool>, IndexerError> {
-    let manager = ConnectionManager::<PgConnection>::new(db_url);
-    // default connection pool max size is 10
-    let pool = Pool::builder().build(manager).map_err(|e| {
-        IndexerError::PgConnectionPoolInitError(format!(
-            "Failed to initialize connection pool with error: {:?}",
-            e
+pub async fn new_pg_connection_pool(
+    config: &IndexerConfig,
+) -> Result<Arc<PgConnectionPool>, IndexerError> {
+    if let Some(min_idle) = config.db_pool_min_idle {
+        if min_idle > config.db_pool_size {
+            return Err(IndexerError::PgConnectionPoolInitError(format!(
+                "db_pool_min_idle ({min_idle}) cannot exceed db_pool_size ({})",
+                config.db_pool_size
+            )));
+        }
+    }
+
+    let manager = ConnectionManager::<PgConnection>::new(config.db_url.clone());
+    let pool = Pool::builder()
+        .max_size(config.db_pool_size)
+        .min_idle(config.db_pool_min_idle)
+        .connection_timeout(std::time::Duration::from_secs(
+            config.db_connection_timeout_seconds,
         ))
-    })?;
+        .idle_timeout(config.db_idle_timeout_seconds.map(std::time::Duration::from_secs))
+        .max_lifetime(config.db_max_lifetime_seconds.map(std::time::Duration::from_secs))
+        .test_on_check_out(config.db_test_on_check_out)
+        .build(manager)
+        .map_err(|e| {
+            IndexerError::PgConnectionPoolInitError(format!(
+                "Failed to initialize connection pool with error: {:?}",
+                e
+            ))
+        })?;
     Ok(Arc::new(pool))
 }
 
 pub fn get_pg_pool_connection(
     pool: Arc<PgConnectionPool>,
+    metrics: &IndexerMetrics,
 ) -> Result<PgPoolConnection, IndexerError> {
-    retry(ExponentialBackoff::default(), || {
+    let start = std::time::Instant::now();
+    let mut retry_count: u64 = 0;
+    let result = retry(ExponentialBackoff::default(), || {
+        retry_count += 1;
         let pool_conn = pool.get()?;
         Ok(pool_conn)
-    })
-    .map_err(|e| {
+    });
+
+    let wait_time = start.elapsed().as_secs_f64();
+    let state = pool.state();
+    metrics
+        .db_conn_pool_idle
+        .set(state.idle_connections as i64);
+    metrics
+        .db_conn_pool_in_use
+        .set((state.connections - state.idle_connections) as i64);
+
+    let label = if result.is_ok() { "success" } else { "error" };
+    metrics
+        .db_conn_pool_wait_time_seconds
+        .with_label_values(&[label])
+        .observe(wait_time);
+    metrics
+        .db_conn_pool_get_retry_count
+        .with_label_values(&[label])
+        .inc_by(retry_count.saturating_sub(1));
+
+    result.map_err(|e| {
         IndexerError::PgPoolConnectionError(format!(
             "Failed to get pool connection from PG connection pool with error: {:?}",
             e
@@ -75,20 +124,69 @@ pub fn get_pg_pool_connection(
     })
 }
 
-pub async fn build_json_rpc_server(
-    prometheus_registry: &Registry,
-    pg_connection_pool: Arc<PgConnectionPool>,
-) -> Result<ServerHandle, IndexerError> {
-    let mut builder =
-        JsonRpcServerBuilder::new(FAKE_PKG_VERSION, prometheus_registry).map_err(|e| {
-            IndexerError::JsonRpcServerError(format!(
-                "Failed to init JSON-RPC builder with error: {:?}",
+/// Builds the blocking thread pool that the checkpoint API's Diesel/PG
+/// queries run on, so a burst of synchronous queries can't starve the async
+/// runtime's own worker threads. The health check does not use this pool:
+/// its `get_pg_pool_connection` call runs on the calling task.
+pub fn new_pg_blocking_pool(config: &IndexerConfig) -> Result<Arc<rayon::ThreadPool>, IndexerError> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(config.db_blocking_pool_size)
+        .thread_name(|i| format!("indexer-pg-blocking-{i}"))
+        .build()
+        .map_err(|e| {
+            IndexerError::PgPoolConnectionError(format!(
+                "Failed to initialize PG blocking thread pool with error: {:?}",
                 e
             ))
         })?;
+    Ok(Arc::new(pool))
+}
+
+/// Handle for the JSON-RPC server [`build_json_rpc_server`] started.
+pub struct IndexerServerHandles {
+    pub http: ServerHandle,
+}
+
+impl IndexerServerHandles {
+    /// Tears down the server.
+    pub async fn stop(self) {
+        let _ = self.http.stop();
+    }
+}
 
+/// Registers on `builder` the modules included in `apis`, plus the health
+/// module, which is registered whatever `apis` holds.
+fn register_modules(
+    mut builder: JsonRpcServerBuilder,
+    apis: &crate::config::ApiSet,
+    pg_connection_pool: Arc<PgConnectionPool>,
+    pg_blocking_pool: Arc<rayon::ThreadPool>,
+    rpc_client: SuiClient,
+    metrics: Arc<IndexerMetrics>,
+) -> Result<JsonRpcServerBuilder, IndexerError> {
+    if apis.includes(CheckpointApiImpl::NAME) {
+        builder
+            .register_module(CheckpointApiImpl::new(
+                pg_connection_pool.clone(),
+                pg_blocking_pool,
+                metrics.clone(),
+            ))
+            .map_err(|e| {
+                IndexerError::JsonRpcServerError(format!(
+                    "Failed to register JSON-RPC module with error: {:?}",
+                    e
+                ))
+            })?;
+    }
+
+    // The health check is always registered, regardless of `ApiSet`, so
+    // operators always have a readiness/liveness signal to probe.
     builder
-        .register_module(CheckpointApiImpl::new(pg_connection_pool))
+        .register_module(HealthApiImpl::new(
+            rpc_client,
+            pg_connection_pool,
+            metrics,
+        ))
         .map_err(|e| {
             IndexerError::JsonRpcServerError(format!(
                 "Failed to register JSON-RPC module with error: {:?}",
@@ -96,12 +194,81 @@ pub async fn build_json_rpc_server(
             ))
         })?;
 
-    // TODO: placeholder, read from env or config file.
-    let default_socket_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 3030);
-    builder.start(default_socket_addr).await.map_err(|e| {
+    Ok(builder)
+}
+
+pub async fn build_json_rpc_server(
+    config: &IndexerConfig,
+    prometheus_registry: &Registry,
+    pg_connection_pool: Arc<PgConnectionPool>,
+    pg_blocking_pool: Arc<rayon::ThreadPool>,
+    rpc_client: SuiClient,
+) -> Result<IndexerServerHandles, IndexerError> {
+    let metrics = Arc::new(IndexerMetrics::new(prometheus_registry));
+
+    let http_config = &config.http;
+    let mut http_builder = JsonRpcServerBuilder::new(&config.package_version, prometheus_registry)
+        .map_err(|e| {
+            IndexerError::JsonRpcServerError(format!(
+                "Failed to init JSON-RPC builder with error: {:?}",
+                e
+            ))
+        })?;
+
+    // CORS and host filtering are applied as tower HTTP middleware via
+    // `set_http_middleware`, which mirrors jsonrpsee's own stable
+    // `ServerBuilder::set_http_middleware` entrypoint for layering arbitrary
+    // `tower` services onto the HTTP transport.
+    let cors_layer = http_config
+        .cors
+        .as_ref()
+        .map(|origins| {
+            let origins = origins
+                .iter()
+                .map(|origin| {
+                    origin.parse::<HeaderValue>().map_err(|e| {
+                        IndexerError::JsonRpcServerError(format!(
+                            "Invalid CORS origin {origin:?}: {:?}",
+                            e
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            // `allow_origin` *replaces* the allowed-origin set rather than
+            // accumulating it, so every origin must go in through a single
+            // `AllowOrigin::list` call instead of one `allow_origin` call per
+            // origin.
+            Ok::<_, IndexerError>(
+                tower_http::cors::CorsLayer::new().allow_origin(AllowOrigin::list(origins)),
+            )
+        })
+        .transpose()?
+        .unwrap_or_else(tower_http::cors::CorsLayer::new);
+    let host_filter_layer = http_config
+        .hosts
+        .clone()
+        .map(crate::http_filter::HostFilterLayer::new);
+    http_builder.set_http_middleware(
+        tower::ServiceBuilder::new()
+            .layer(cors_layer)
+            .option_layer(host_filter_layer),
+    );
+
+    let http_builder = register_modules(
+        http_builder,
+        &http_config.apis,
+        pg_connection_pool,
+        pg_blocking_pool,
+        rpc_client,
+        metrics,
+    )?;
+    let socket_addr = SocketAddr::new(http_config.interface, http_config.port);
+    let http = http_builder.start(socket_addr).await.map_err(|e| {
         IndexerError::JsonRpcServerError(format!(
             "Failed to start JSON-RPC server with error: {:?}",
             e
         ))
-    })
+    })?;
+
+    Ok(IndexerServerHandles { http })
 }
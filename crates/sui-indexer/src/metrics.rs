@@ -0,0 +1,140 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use prometheus::{
+    register_histogram_vec_with_registry, register_int_counter_vec_with_registry,
+    register_int_gauge_with_registry, HistogramVec, IntCounterVec, IntGauge, Registry,
+};
+
+/// Prometheus metrics tracking PG connection pool health and per-method
+/// JSON-RPC latency/errors, so operators can see pool saturation and request
+/// health instead of flying blind.
+#[derive(Clone)]
+pub struct IndexerMetrics {
+    /// Connections currently checked out of the pool.
+    pub db_conn_pool_in_use: IntGauge,
+    /// Idle connections currently sitting in the pool.
+    pub db_conn_pool_idle: IntGauge,
+    /// How long callers waited in `get_pg_pool_connection`, including
+    /// retries, labeled by whether a connection was obtained (`success`) or
+    /// not (`error`).
+    pub db_conn_pool_wait_time_seconds: HistogramVec,
+    /// How many times `get_pg_pool_connection`'s `ExponentialBackoff` loop
+    /// retried, labeled by whether a connection was obtained (`success`) or
+    /// not (`error`).
+    pub db_conn_pool_get_retry_count: IntCounterVec,
+    /// Latency of each JSON-RPC method, labeled by method name.
+    pub rpc_request_latency_seconds: HistogramVec,
+    /// Errors returned by each JSON-RPC method, labeled by method name.
+    pub rpc_request_errors: IntCounterVec,
+}
+
+impl IndexerMetrics {
+    pub fn new(registry: &Registry) -> Self {
+        Self {
+            db_conn_pool_in_use: register_int_gauge_with_registry!(
+                "indexer_db_conn_pool_in_use",
+                "Number of PG connections currently checked out of the pool",
+                registry,
+            )
+            .unwrap(),
+            db_conn_pool_idle: register_int_gauge_with_registry!(
+                "indexer_db_conn_pool_idle",
+                "Number of idle PG connections currently sitting in the pool",
+                registry,
+            )
+            .unwrap(),
+            db_conn_pool_wait_time_seconds: register_histogram_vec_with_registry!(
+                "indexer_db_conn_pool_wait_time_seconds",
+                "Time spent waiting for a PG pool connection, including retries",
+                &["result"],
+                registry,
+            )
+            .unwrap(),
+            db_conn_pool_get_retry_count: register_int_counter_vec_with_registry!(
+                "indexer_db_conn_pool_get_retry_count",
+                "Number of retries taken getting a PG pool connection, by result",
+                &["result"],
+                registry,
+            )
+            .unwrap(),
+            rpc_request_latency_seconds: register_histogram_vec_with_registry!(
+                "indexer_rpc_request_latency_seconds",
+                "Latency of JSON-RPC requests by method",
+                &["method"],
+                registry,
+            )
+            .unwrap(),
+            rpc_request_errors: register_int_counter_vec_with_registry!(
+                "indexer_rpc_request_errors",
+                "Number of JSON-RPC requests that returned an error, by method",
+                &["method"],
+                registry,
+            )
+            .unwrap(),
+        }
+    }
+
+    /// Times `f` and records it under `rpc_request_latency_seconds{method}`,
+    /// also bumping `rpc_request_errors{method}` if it returns `Err`.
+    pub async fn observe_rpc<F, Fut, T, E>(&self, method: &str, f: F) -> Result<T, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<T, E>>,
+    {
+        let start = std::time::Instant::now();
+        let result = f().await;
+        self.rpc_request_latency_seconds
+            .with_label_values(&[method])
+            .observe(start.elapsed().as_secs_f64());
+        if result.is_err() {
+            self.rpc_request_errors.with_label_values(&[method]).inc();
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn observe_rpc_records_latency_and_errors() {
+        let registry = Registry::new();
+        let metrics = IndexerMetrics::new(&registry);
+
+        metrics
+            .observe_rpc("ok_method", || async { Ok::<_, IndexerErrorStub>(42) })
+            .await
+            .unwrap();
+        metrics
+            .observe_rpc("err_method", || async { Err::<i32, _>(IndexerErrorStub) })
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            metrics
+                .rpc_request_latency_seconds
+                .with_label_values(&["ok_method"])
+                .get_sample_count(),
+            1
+        );
+        assert_eq!(
+            metrics
+                .rpc_request_errors
+                .with_label_values(&["ok_method"])
+                .get(),
+            0
+        );
+        assert_eq!(
+            metrics
+                .rpc_request_errors
+                .with_label_values(&["err_method"])
+                .get(),
+            1
+        );
+    }
+
+    #[derive(Debug)]
+    struct IndexerErrorStub;
+}